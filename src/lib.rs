@@ -28,10 +28,12 @@
 #![warn(missing_docs)]
 
 use std::{
-    collections::HashMap,
+    any::{Any, TypeId},
+    collections::{HashMap, HashSet},
     hash::Hash,
     marker::PhantomData,
     path::{Path, PathBuf},
+    time::SystemTime,
 };
 
 /// Trait for types that can be loaded as assets
@@ -39,28 +41,64 @@ pub trait Asset: Sized + 'static {
     /// What additional resources are required to load this asset.
     /// For assets that can be loaded using global resources, this can just be ```()```
     type Resources;
-    /// What type of error is returned when the asset can't be loaded
-    type Error: std::fmt::Display + std::fmt::Debug;
+    /// What type of error is returned when the asset can't be loaded.
+    ///
+    /// Required to be `Clone` so that a failed load's error can be recorded
+    /// in both the [`AssetHandle`]'s state and the manager's failure queue
+    /// (see [`AssetManager::drain_failures`]).
+    type Error: std::fmt::Display + std::fmt::Debug + Clone;
 
     /// Loads an asset from a given path
     /// # Errors
     /// This function returns an error if the asset could not be loaded
     fn load(path: impl AsRef<Path>, resources: &Self::Resources) -> Result<Self, Self::Error>;
+
+    /// Loads an asset from a given path, optionally selecting a named
+    /// sub-asset (`label`) from within that file, e.g. one region of a
+    /// sprite atlas or one mesh in a multi-mesh glTF.
+    ///
+    /// The default implementation ignores `label` and just calls
+    /// [`load`](Self::load), for assets that don't have sub-asset labels.
+    /// Implementations that do support labels should return a distinct
+    /// error when `label` is `Some` but not found in the source file,
+    /// rather than silently falling back to the whole file.
+    /// # Errors
+    /// This function returns an error if the asset (or labeled sub-asset)
+    /// could not be loaded.
+    fn load_labeled(
+        path: impl AsRef<Path>,
+        label: Option<&str>,
+        resources: &Self::Resources,
+    ) -> Result<Self, Self::Error> {
+        let _ = label;
+        Self::load(path, resources)
+    }
+}
+
+/// Splits a handle path of the form `"atlas.png#walk_left"` into its base
+/// path and label.
+fn split_label(path: &Path) -> (PathBuf, Option<Box<str>>) {
+    match path.to_string_lossy().split_once('#') {
+        Some((base, label)) if !label.is_empty() => (PathBuf::from(base), Some(label.into())),
+        _ => (path.to_path_buf(), None),
+    }
 }
 
 #[derive(Clone)]
 enum AssetState<E> {
     Loaded(usize),
-    Unloaded(PathBuf),
-    Error(PathBuf, E),
+    Unloaded(PathBuf, Option<Box<str>>),
+    Error(PathBuf, Option<Box<str>>, E),
 }
 
 impl<E> PartialEq for AssetState<E> {
     fn eq(&self, other: &Self) -> bool {
         match (self, other) {
             (Self::Loaded(idx1), Self::Loaded(idx2)) => idx1 == idx2,
-            (Self::Unloaded(path1), Self::Unloaded(path2))
-            | (Self::Error(path1, _), Self::Error(path2, _)) => path1 == path2,
+            (Self::Unloaded(path1, label1), Self::Unloaded(path2, label2))
+            | (Self::Error(path1, label1, _), Self::Error(path2, label2, _)) => {
+                path1 == path2 && label1 == label2
+            }
             _ => false,
         }
     }
@@ -73,22 +111,34 @@ impl<E> Hash for AssetState<E> {
         std::mem::discriminant(self).hash(state);
         match self {
             Self::Loaded(idx) => idx.hash(state),
-            Self::Unloaded(path) | Self::Error(path, _) => path.hash(state),
+            Self::Unloaded(path, label) | Self::Error(path, label, _) => {
+                path.hash(state);
+                label.hash(state);
+            }
         }
     }
 }
 
 /// A handle to an asset of type `T`. Used with an [`AssetManager<T>`].
+///
+/// Deliberately does not implement `Clone`: once loaded, a handle counts as
+/// one live reference to its slot (see [`AssetManager::release`] and
+/// [`AssetManager::unload_unused`]), and a bare `Clone` would let a copy
+/// outlive the count that keeps its slot alive. Use
+/// [`AssetManager::clone_handle`] instead, which bumps the reference count.
 pub struct AssetHandle<T: Asset> {
     state: AssetState<T::Error>,
     _asset: PhantomData<T>,
 }
 
 impl<T: Asset> AssetHandle<T> {
-    /// Creates a new handle for an unloaded asset.
+    /// Creates a new handle for an unloaded asset. `path` may carry a
+    /// `"#label"` suffix (e.g. `"atlas.png#walk_left"`) to address a named
+    /// sub-asset within the source file.
     pub fn new(path: impl AsRef<Path>) -> Self {
+        let (path, label) = split_label(path.as_ref());
         Self {
-            state: AssetState::Unloaded(path.as_ref().into()),
+            state: AssetState::Unloaded(path, label),
             _asset: PhantomData,
         }
     }
@@ -97,7 +147,17 @@ impl<T: Asset> AssetHandle<T> {
     /// Returns the path to the asset if it is still unloaded, otherwise returns `None`.
     pub fn path(&self) -> Option<&Path> {
         match &self.state {
-            AssetState::Unloaded(p) | AssetState::Error(p, _) => Some(p.as_path()),
+            AssetState::Unloaded(p, _) | AssetState::Error(p, _, _) => Some(p.as_path()),
+            _ => None,
+        }
+    }
+
+    #[must_use]
+    /// Returns the sub-asset label this handle addresses, if any, while the
+    /// handle is unloaded or in the error state. Returns `None` once loaded.
+    pub fn label(&self) -> Option<&str> {
+        match &self.state {
+            AssetState::Unloaded(_, l) | AssetState::Error(_, l, _) => l.as_deref(),
             _ => None,
         }
     }
@@ -105,7 +165,7 @@ impl<T: Asset> AssetHandle<T> {
     #[must_use]
     /// Returns true if the asset hasn't been loaded yet.
     pub fn is_unloaded(&self) -> bool {
-        matches!(self.state, AssetState::Unloaded(_))
+        matches!(self.state, AssetState::Unloaded(_, _))
     }
 
     #[must_use]
@@ -117,7 +177,7 @@ impl<T: Asset> AssetHandle<T> {
     #[must_use]
     /// Returns true if the asset previously failed to load.
     pub fn is_err(&self) -> bool {
-        matches!(self.state, AssetState::Error(_, _))
+        matches!(self.state, AssetState::Error(_, _, _))
     }
 }
 
@@ -135,18 +195,6 @@ impl<T: Asset> Hash for AssetHandle<T> {
     }
 }
 
-impl<T: Asset> Clone for AssetHandle<T>
-where
-    T::Error: Clone,
-{
-    fn clone(&self) -> Self {
-        Self {
-            state: self.state.clone(),
-            _asset: PhantomData,
-        }
-    }
-}
-
 /// Safety: Since handles don't actually contain the asset, it's safe to send
 /// one to another thread even if the asset itself isn't `Send`.
 unsafe impl<T: Asset> Send for AssetHandle<T> {}
@@ -154,10 +202,39 @@ unsafe impl<T: Asset> Send for AssetHandle<T> {}
 /// one between threads even if the asset itself isn't or `Sync`.
 unsafe impl<T: Asset> Sync for AssetHandle<T> {}
 
+/// A loaded asset together with the bookkeeping needed for hot-reloading and
+/// reference-counted eviction.
+struct Slot<T> {
+    asset: T,
+    path: PathBuf,
+    label: Option<Box<str>>,
+    modified: Option<SystemTime>,
+    /// How many live handles currently reference this slot. Once this drops
+    /// to zero, [`AssetManager::unload_unused`] may reclaim the slot.
+    ref_count: u32,
+}
+
 /// A loader for [`Assets`](Asset)
 pub struct AssetManager<T: Asset> {
-    assets: Vec<T>,
-    paths: HashMap<PathBuf, usize>,
+    /// Loaded asset slots, indexed by the index stored in `AssetState::Loaded`.
+    /// `None` marks a freed slot awaiting reuse (tracked in `free_list`).
+    slots: Vec<Option<Slot<T>>>,
+    /// Indices of freed slots in `slots`, reused by future loads instead of
+    /// growing the vector, so existing `Loaded(idx)` handles stay valid.
+    free_list: Vec<usize>,
+    paths: HashMap<(PathBuf, Option<Box<str>>), usize>,
+    /// When `Some`, only paths in this set are re-stated by `reload_changed`,
+    /// rather than every tracked source. Populated by [`mark_dirty`](Self::mark_dirty),
+    /// typically from a filesystem watcher's event callback.
+    dirty: Option<HashSet<PathBuf>>,
+    /// How many times a load of each (path, label) has been attempted,
+    /// including retries, since its last successful load (or since it was
+    /// last evicted by [`unload_unused`](Self::unload_unused)). Used to
+    /// stamp [`AssetLoadFailure::attempts`].
+    attempts: HashMap<(PathBuf, Option<Box<str>>), u32>,
+    /// Queued failures from failed load attempts, drained by
+    /// [`drain_failures`](Self::drain_failures).
+    failures: Vec<AssetLoadFailure<T::Error>>,
 }
 
 impl<T: Asset> AssetManager<T> {
@@ -166,11 +243,61 @@ impl<T: Asset> AssetManager<T> {
         Self::default()
     }
 
+    /// Creates a new `AssetManager<T>` whose [`reload_changed`](Self::reload_changed)
+    /// only re-stats paths that have been explicitly marked with
+    /// [`mark_dirty`](Self::mark_dirty), instead of every tracked source.
+    ///
+    /// This is meant to be driven by a push-based filesystem watcher (such as
+    /// the `notify` crate): have its event callback call `mark_dirty` for
+    /// each path it reports as changed.
+    pub fn with_watching() -> Self {
+        Self {
+            dirty: Some(HashSet::new()),
+            ..Self::default()
+        }
+    }
+
+    /// Marks `path` as changed, so the next [`reload_changed`](Self::reload_changed)
+    /// call will re-check it. Has no effect unless this manager was created
+    /// with [`with_watching`](Self::with_watching).
+    pub fn mark_dirty(&mut self, path: impl AsRef<Path>) {
+        if let Some(dirty) = &mut self.dirty {
+            dirty.insert(path.as_ref().to_path_buf());
+        }
+    }
+
     /// Returns a reference to a loaded asset
     pub fn get(&self, handle: &AssetHandle<T>) -> Option<&T> {
         match handle.state {
-            AssetState::Loaded(idx) => Some(&self.assets[idx]),
-            AssetState::Unloaded(_) | AssetState::Error(_, _) => None,
+            AssetState::Loaded(idx) => self.slots[idx].as_ref().map(|slot| &slot.asset),
+            AssetState::Unloaded(_, _) | AssetState::Error(_, _, _) => None,
+        }
+    }
+
+    /// Creates a new handle referring to the same asset as `handle`,
+    /// incrementing its reference count if it's currently loaded. Unlike a
+    /// bare field-for-field copy, this keeps the slot's `ref_count` in sync
+    /// so [`unload_unused`](Self::unload_unused) won't reclaim the slot while
+    /// the clone is still alive.
+    pub fn clone_handle(&mut self, handle: &AssetHandle<T>) -> AssetHandle<T> {
+        if let AssetState::Loaded(idx) = handle.state {
+            if let Some(slot) = self.slots[idx].as_mut() {
+                slot.ref_count += 1;
+            }
+        }
+        AssetHandle {
+            state: handle.state.clone(),
+            _asset: PhantomData,
+        }
+    }
+
+    fn alloc_slot(&mut self, slot: Slot<T>) -> usize {
+        if let Some(idx) = self.free_list.pop() {
+            self.slots[idx] = Some(slot);
+            idx
+        } else {
+            self.slots.push(Some(slot));
+            self.slots.len() - 1
         }
     }
 
@@ -186,37 +313,276 @@ impl<T: Asset> AssetManager<T> {
         resources: &T::Resources,
     ) -> Result<(), &'a T::Error> {
         match &handle.state {
-            AssetState::Loaded(_) | AssetState::Error(_, _) => Ok(()),
-            AssetState::Unloaded(path) => match self.paths.get(path) {
-                Some(&idx) => {
-                    handle.state = AssetState::Loaded(idx);
-                    Ok(())
-                }
-                None => {
-                    log::debug!(
-                        "Loading asset '{}' of type '{}'",
-                        path.display(),
-                        std::any::type_name::<T>()
-                    );
-                    let idx = self.assets.len();
-                    let loaded_asset = T::load(path, resources);
-                    match loaded_asset {
-                        Ok(loaded_asset) => {
-                            self.assets.push(loaded_asset);
-                            self.paths.insert(path.clone(), idx);
-                            handle.state = AssetState::Loaded(idx);
-                            Ok(())
+            AssetState::Loaded(_) | AssetState::Error(_, _, _) => Ok(()),
+            AssetState::Unloaded(path, label) => {
+                let key = (path.clone(), label.clone());
+                match self.paths.get(&key).copied() {
+                    Some(idx) => {
+                        if let Some(slot) = self.slots[idx].as_mut() {
+                            slot.ref_count += 1;
                         }
-                        Err(e) => {
-                            handle.state = AssetState::Error(path.clone(), e);
-                            match &handle.state {
-                                AssetState::Error(_, e) => Err(e),
-                                _ => unreachable!(),
+                        handle.state = AssetState::Loaded(idx);
+                        Ok(())
+                    }
+                    None => {
+                        log::debug!(
+                            "Loading asset '{}' (label {:?}) of type '{}'",
+                            path.display(),
+                            label,
+                            std::any::type_name::<T>()
+                        );
+                        let loaded_asset = T::load_labeled(path, label.as_deref(), resources);
+                        match loaded_asset {
+                            Ok(asset) => {
+                                let modified = modified_time(path);
+                                let idx = self.alloc_slot(Slot {
+                                    asset,
+                                    path: path.clone(),
+                                    label: label.clone(),
+                                    modified,
+                                    ref_count: 1,
+                                });
+                                self.attempts.remove(&key);
+                                self.paths.insert(key, idx);
+                                handle.state = AssetState::Loaded(idx);
+                                Ok(())
+                            }
+                            Err(e) => {
+                                let attempts = self.attempts.entry(key).or_insert(0);
+                                *attempts += 1;
+                                self.failures.push(AssetLoadFailure {
+                                    path: path.clone(),
+                                    label: label.clone(),
+                                    error: e.clone(),
+                                    attempts: *attempts,
+                                });
+                                handle.state = AssetState::Error(path.clone(), label.clone(), e);
+                                match &handle.state {
+                                    AssetState::Error(_, _, e) => Err(e),
+                                    _ => unreachable!(),
+                                }
                             }
                         }
                     }
                 }
-            },
+            }
+        }
+    }
+
+    /// Decrements the reference count of the asset `handle` refers to and
+    /// resets `handle` back to `Unloaded`. Does nothing if `handle` isn't
+    /// currently loaded. The asset itself isn't freed until
+    /// [`unload_unused`](Self::unload_unused) is called.
+    pub fn release(&mut self, handle: &mut AssetHandle<T>) {
+        if let AssetState::Loaded(idx) = handle.state {
+            if let Some(slot) = self.slots[idx].as_mut() {
+                slot.ref_count = slot.ref_count.saturating_sub(1);
+                handle.state = AssetState::Unloaded(slot.path.clone(), slot.label.clone());
+            }
+        }
+    }
+
+    /// Drops every loaded asset whose reference count has reached zero,
+    /// reclaiming its slot for reuse by a future [`load`](Self::load) and
+    /// removing its entry from the path cache so a later load re-reads it
+    /// from disk. Slots for assets still in use are left untouched, so
+    /// existing `Loaded(idx)` handles for them stay valid.
+    pub fn unload_unused(&mut self) {
+        for idx in 0..self.slots.len() {
+            let unused = matches!(&self.slots[idx], Some(slot) if slot.ref_count == 0);
+            if !unused {
+                continue;
+            }
+            if let Some(slot) = self.slots[idx].take() {
+                let key = (slot.path, slot.label);
+                self.paths.remove(&key);
+                self.attempts.remove(&key);
+            }
+            self.free_list.push(idx);
+        }
+    }
+
+    /// Returns and clears all load failures queued up since the last call.
+    pub fn drain_failures(&mut self) -> Vec<AssetLoadFailure<T::Error>> {
+        std::mem::take(&mut self.failures)
+    }
+
+    /// Resets a handle that previously failed to load back to `Unloaded` and
+    /// attempts to load it again, incrementing its attempt counter. Does
+    /// nothing (and returns `Ok`) if the handle isn't currently in the
+    /// `Error` state.
+    /// # Errors
+    /// Returns an error if the [load_labeled](Asset::load_labeled) method returns an error.
+    pub fn retry<'a>(
+        &mut self,
+        handle: &'a mut AssetHandle<T>,
+        resources: &T::Resources,
+    ) -> Result<(), &'a T::Error> {
+        let AssetState::Error(path, label, _) = &handle.state else {
+            return Ok(());
+        };
+        handle.state = AssetState::Unloaded(path.clone(), label.clone());
+        self.load(handle, resources)
+    }
+
+    /// Calls [`retry`](Self::retry) on every handle in `handles` that is
+    /// currently in the `Error` state, ignoring the individual results (use
+    /// [`drain_failures`](Self::drain_failures) to inspect failures
+    /// afterwards).
+    pub fn retry_all<'a>(
+        &mut self,
+        handles: impl IntoIterator<Item = &'a mut AssetHandle<T>>,
+        resources: &T::Resources,
+    ) where
+        T::Error: 'a,
+    {
+        for handle in handles {
+            if handle.is_err() {
+                let _ = self.retry(handle, resources);
+            }
+        }
+    }
+
+    /// Re-checks tracked source files for changes and reloads any asset
+    /// whose file's modified time has advanced since it was last loaded (or
+    /// reloaded), replacing it in place so existing [`AssetHandle`]s
+    /// transparently observe the new value.
+    ///
+    /// If this manager was created with [`with_watching`](Self::with_watching),
+    /// only paths marked via [`mark_dirty`](Self::mark_dirty) since the last
+    /// call are re-stated; otherwise every tracked source is re-stated.
+    pub fn reload_changed(&mut self, resources: &T::Resources) -> ReloadReport<T::Error> {
+        let mut report = ReloadReport::default();
+        let watching = self.dirty.is_some();
+        let candidates: HashSet<PathBuf> = match self.dirty.take() {
+            Some(dirty) => dirty,
+            None => self
+                .slots
+                .iter()
+                .flatten()
+                .map(|slot| slot.path.clone())
+                .collect(),
+        };
+        for idx in 0..self.slots.len() {
+            let Some(slot) = &self.slots[idx] else {
+                continue;
+            };
+            if !candidates.contains(&slot.path) {
+                continue;
+            }
+            let Some(modified) = modified_time(&slot.path) else {
+                continue;
+            };
+            if slot.modified.is_some_and(|last_seen| modified <= last_seen) {
+                continue;
+            }
+            let path = slot.path.clone();
+            let label = slot.label.clone();
+            log::debug!(
+                "Reloading asset '{}' (label {:?}) of type '{}'",
+                path.display(),
+                label,
+                std::any::type_name::<T>()
+            );
+            match T::load_labeled(&path, label.as_deref(), resources) {
+                Ok(asset) => {
+                    let slot = self.slots[idx].as_mut().expect("slot checked above");
+                    slot.asset = asset;
+                    slot.modified = Some(modified);
+                    report.reloaded.push(path);
+                }
+                Err(e) => report.errors.push((path, e)),
+            }
+        }
+        if watching {
+            self.dirty = Some(HashSet::new());
+        }
+        report
+    }
+}
+
+fn modified_time(path: &Path) -> Option<SystemTime> {
+    std::fs::metadata(path).ok()?.modified().ok()
+}
+
+/// An [`AssetManager`] shared so that [`AssetGuard`]s can release their
+/// asset when dropped.
+pub type SharedAssetManager<T> = std::rc::Rc<std::cell::RefCell<AssetManager<T>>>;
+
+/// An owning handle to a loaded asset that automatically
+/// [`release`](AssetManager::release)s it when dropped, unlike the bare
+/// [`AssetHandle`] (which requires an explicit `release` call).
+pub struct AssetGuard<T: Asset> {
+    manager: SharedAssetManager<T>,
+    handle: AssetHandle<T>,
+}
+
+impl<T: Asset> AssetGuard<T> {
+    /// Loads an asset through `manager` and wraps it in a guard that
+    /// releases it automatically on drop.
+    /// # Errors
+    /// Returns an error if the [load_labeled](Asset::load_labeled) method returns an error.
+    pub fn load(
+        manager: &SharedAssetManager<T>,
+        path: impl AsRef<Path>,
+        resources: &T::Resources,
+    ) -> Result<Self, T::Error> {
+        let mut handle = AssetHandle::new(path);
+        manager
+            .borrow_mut()
+            .load(&mut handle, resources)
+            .map_err(Clone::clone)?;
+        Ok(Self {
+            manager: manager.clone(),
+            handle,
+        })
+    }
+
+    /// Returns a reference to the underlying asset.
+    #[must_use]
+    pub fn get(&self) -> std::cell::Ref<'_, T> {
+        std::cell::Ref::map(std::cell::RefCell::borrow(&self.manager), |manager| {
+            manager
+                .get(&self.handle)
+                .expect("AssetGuard always refers to a loaded asset")
+        })
+    }
+}
+
+impl<T: Asset> Drop for AssetGuard<T> {
+    fn drop(&mut self) {
+        self.manager.borrow_mut().release(&mut self.handle);
+    }
+}
+
+/// A record of a single failed attempt to load an asset, queued by
+/// [`AssetManager`] and retrieved via [`AssetManager::drain_failures`].
+pub struct AssetLoadFailure<E> {
+    /// The path the asset failed to load from.
+    pub path: PathBuf,
+    /// The sub-asset label that was requested, if any.
+    pub label: Option<Box<str>>,
+    /// The error returned by [`Asset::load_labeled`].
+    pub error: E,
+    /// How many times a load of this path and label has been attempted,
+    /// including this one, since its last successful load or eviction.
+    pub attempts: u32,
+}
+
+/// The outcome of a single [`AssetManager::reload_changed`] call.
+pub struct ReloadReport<E> {
+    /// Paths whose asset was successfully reloaded.
+    pub reloaded: Vec<PathBuf>,
+    /// Paths that changed but failed to reload, paired with the error. The
+    /// previously loaded value is left in place for these.
+    pub errors: Vec<(PathBuf, E)>,
+}
+
+impl<E> Default for ReloadReport<E> {
+    fn default() -> Self {
+        Self {
+            reloaded: Vec::new(),
+            errors: Vec::new(),
         }
     }
 }
@@ -224,8 +590,319 @@ impl<T: Asset> AssetManager<T> {
 impl<T: Asset> Default for AssetManager<T> {
     fn default() -> Self {
         Self {
-            assets: Vec::default(),
+            slots: Vec::default(),
+            free_list: Vec::default(),
             paths: HashMap::default(),
+            dirty: None,
+            attempts: HashMap::default(),
+            failures: Vec::default(),
+        }
+    }
+}
+
+/// A key identifying a cache entry in an [`AssetCache`] by its path (as a
+/// string) and the [`TypeId`] of the asset stored there.
+#[derive(PartialEq, Eq, Hash)]
+struct Key {
+    id: Box<str>,
+    type_id: TypeId,
+}
+
+/// Allocates an owned string form of `path` for use in a [`Key`]. This is a
+/// lossy UTF-8 conversion only; the path is not otherwise normalized, so for
+/// example `a/b` and `a/./b` give different keys.
+///
+/// This allocates a `Box<str>` on every call, including `get` lookups that
+/// don't end up inserting anything. An earlier version of [`Key`] avoided
+/// that allocation with an unsound `Borrow`-based transmute; removing it
+/// brought back the allocation on each lookup. A zero-allocation lookup is
+/// possible (e.g. via `hashbrown`'s raw-entry API, keyed on `&str` +
+/// `TypeId`), but `AssetCache` isn't on a hot enough path to justify the
+/// extra dependency and API surface for it yet — paying one allocation per
+/// `load`/`get` call is an accepted tradeoff until that changes.
+fn normalize_id(path: &Path) -> Box<str> {
+    path.to_string_lossy().into()
+}
+
+/// A cache that stores assets of any [`Asset`] type in a single map, keyed by
+/// the combination of their source path and their Rust type.
+///
+/// Unlike [`AssetManager<T>`], which is monomorphic over one asset type, an
+/// `AssetCache` lets a single path back several different asset types (for
+/// example loading both a `Mesh` and a `Blob` from the same glTF file)
+/// without needing one manager per type.
+///
+/// `AssetCache` does not (yet) have the hot-reloading, failure/retry queue,
+/// labeled sub-asset, or reference-counted eviction support that
+/// [`AssetManager<T>`] has; pick `AssetManager<T>` instead if you need those
+/// and only have one asset type per path.
+#[derive(Default)]
+pub struct AssetCache {
+    entries: HashMap<Key, Box<dyn Any>>,
+}
+
+impl AssetCache {
+    /// Creates a new, empty `AssetCache`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Loads an asset of type `T` from `path` and inserts it into the cache.
+    /// Does nothing if an asset of type `T` is already cached for that path.
+    /// # Errors
+    /// Returns an error if the [load](Asset::load) method returns an error.
+    pub fn load<T: Asset>(
+        &mut self,
+        path: impl AsRef<Path>,
+        resources: &T::Resources,
+    ) -> Result<(), T::Error> {
+        let path = path.as_ref();
+        let key = Key {
+            id: normalize_id(path),
+            type_id: TypeId::of::<T>(),
+        };
+        if self.entries.contains_key(&key) {
+            return Ok(());
+        }
+        log::debug!(
+            "Loading asset '{}' of type '{}'",
+            path.display(),
+            std::any::type_name::<T>()
+        );
+        let asset = T::load(path, resources)?;
+        self.entries.insert(key, Box::new(asset));
+        Ok(())
+    }
+
+    /// Returns a reference to a cached asset of type `T` previously loaded
+    /// from `path`, or `None` if no such asset is cached.
+    #[must_use]
+    pub fn get<T: Asset>(&self, path: impl AsRef<Path>) -> Option<&T> {
+        let key = Key {
+            id: normalize_id(path.as_ref()),
+            type_id: TypeId::of::<T>(),
+        };
+        self.entries
+            .get(&key)
+            .and_then(|boxed| boxed.downcast_ref::<T>())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, PartialEq, Eq, Clone)]
+    struct Tagged(String);
+
+    impl Asset for Tagged {
+        type Resources = ();
+        type Error = String;
+
+        fn load(path: impl AsRef<Path>, _resources: &Self::Resources) -> Result<Self, Self::Error> {
+            Ok(Tagged(path.as_ref().to_string_lossy().into_owned()))
+        }
+    }
+
+    #[test]
+    fn clone_handle_keeps_slot_alive_until_all_clones_released() {
+        let mut mgr: AssetManager<Tagged> = AssetManager::new();
+        let mut a = AssetHandle::new("111");
+        mgr.load(&mut a, &()).unwrap();
+        let mut b = mgr.clone_handle(&a);
+
+        mgr.release(&mut a);
+        mgr.unload_unused();
+
+        let mut other = AssetHandle::new("222");
+        mgr.load(&mut other, &()).unwrap();
+
+        assert_eq!(mgr.get(&b), Some(&Tagged("111".to_string())));
+        assert_eq!(mgr.get(&other), Some(&Tagged("222".to_string())));
+
+        mgr.release(&mut b);
+        mgr.unload_unused();
+    }
+
+    /// Returns a path under a per-test-run temp directory, creating the
+    /// directory if needed. Used by tests that need a real file to stat.
+    fn temp_path(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("asset_manager_tests_{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        dir.join(name)
+    }
+
+    #[test]
+    fn reload_changed_reloads_on_bumped_mtime() {
+        let path = temp_path("reload_changed.txt");
+        std::fs::write(&path, "one").unwrap();
+
+        let mut mgr: AssetManager<Tagged> = AssetManager::new();
+        let mut handle = AssetHandle::new(path.to_str().unwrap());
+        mgr.load(&mut handle, &()).unwrap();
+
+        let report = mgr.reload_changed(&());
+        assert!(
+            report.reloaded.is_empty(),
+            "an untouched file shouldn't be reported as reloaded"
+        );
+
+        let future = SystemTime::now() + std::time::Duration::from_secs(2);
+        std::fs::File::open(&path).unwrap().set_modified(future).unwrap();
+
+        let report = mgr.reload_changed(&());
+        assert_eq!(report.reloaded, vec![path.clone()]);
+        assert!(report.errors.is_empty());
+
+        std::fs::remove_file(&path).ok();
+    }
+
+    #[test]
+    fn with_watching_only_reloads_paths_marked_dirty() {
+        let path_a = temp_path("watched_a.txt");
+        let path_b = temp_path("watched_b.txt");
+        std::fs::write(&path_a, "a").unwrap();
+        std::fs::write(&path_b, "b").unwrap();
+
+        let mut mgr: AssetManager<Tagged> = AssetManager::with_watching();
+        let mut handle_a = AssetHandle::new(path_a.to_str().unwrap());
+        let mut handle_b = AssetHandle::new(path_b.to_str().unwrap());
+        mgr.load(&mut handle_a, &()).unwrap();
+        mgr.load(&mut handle_b, &()).unwrap();
+
+        let future = SystemTime::now() + std::time::Duration::from_secs(2);
+        std::fs::File::open(&path_a).unwrap().set_modified(future).unwrap();
+        std::fs::File::open(&path_b).unwrap().set_modified(future).unwrap();
+
+        // Only `path_a` was marked dirty, so `path_b`'s bumped mtime must be
+        // ignored even though it changed too.
+        mgr.mark_dirty(&path_a);
+        let report = mgr.reload_changed(&());
+        assert_eq!(report.reloaded, vec![path_a.clone()]);
+
+        std::fs::remove_file(&path_a).ok();
+        std::fs::remove_file(&path_b).ok();
+    }
+
+    struct Flaky;
+
+    impl Asset for Flaky {
+        type Resources = std::cell::Cell<bool>;
+        type Error = String;
+
+        fn load(path: impl AsRef<Path>, should_succeed: &Self::Resources) -> Result<Self, Self::Error> {
+            if should_succeed.get() {
+                Ok(Flaky)
+            } else {
+                Err(format!("failed to load {}", path.as_ref().display()))
+            }
         }
     }
+
+    #[test]
+    fn attempts_counter_resets_after_success_and_after_eviction() {
+        let mut mgr: AssetManager<Flaky> = AssetManager::new();
+        let resources = std::cell::Cell::new(false);
+        let mut handle = AssetHandle::new("flaky");
+
+        mgr.load(&mut handle, &resources).unwrap_err();
+        mgr.retry(&mut handle, &resources).unwrap_err();
+        let failures = mgr.drain_failures();
+        assert_eq!(failures.len(), 2);
+        assert_eq!(failures[1].attempts, 2);
+
+        resources.set(true);
+        mgr.retry(&mut handle, &resources).unwrap();
+        mgr.release(&mut handle);
+        mgr.unload_unused();
+
+        resources.set(false);
+        let mut handle2 = AssetHandle::new("flaky");
+        mgr.load(&mut handle2, &resources).unwrap_err();
+        let failures = mgr.drain_failures();
+        assert_eq!(failures.len(), 1);
+        assert_eq!(
+            failures[0].attempts, 1,
+            "attempts counter should restart after a successful load and eviction, not keep counting"
+        );
+    }
+
+    #[test]
+    fn asset_cache_stores_multiple_types_per_path() {
+        let mut cache = AssetCache::new();
+        cache.load::<Tagged>("shared", &()).unwrap();
+
+        assert_eq!(
+            cache.get::<Tagged>("shared"),
+            Some(&Tagged("shared".to_string()))
+        );
+        assert!(cache.get::<Flaky>("shared").is_none());
+
+        cache
+            .load::<Flaky>("shared", &std::cell::Cell::new(true))
+            .unwrap();
+        assert!(cache.get::<Flaky>("shared").is_some());
+        assert_eq!(
+            cache.get::<Tagged>("shared"),
+            Some(&Tagged("shared".to_string()))
+        );
+    }
+
+    #[derive(Debug, PartialEq, Eq, Clone)]
+    struct Labeled(String);
+
+    impl Asset for Labeled {
+        type Resources = ();
+        type Error = String;
+
+        fn load(path: impl AsRef<Path>, resources: &Self::Resources) -> Result<Self, Self::Error> {
+            Self::load_labeled(path, None, resources)
+        }
+
+        fn load_labeled(
+            path: impl AsRef<Path>,
+            label: Option<&str>,
+            _resources: &Self::Resources,
+        ) -> Result<Self, Self::Error> {
+            match label {
+                Some("missing") => Err(format!(
+                    "no sub-asset 'missing' in {}",
+                    path.as_ref().display()
+                )),
+                _ => Ok(Labeled(format!(
+                    "{}#{}",
+                    path.as_ref().display(),
+                    label.unwrap_or("-")
+                ))),
+            }
+        }
+    }
+
+    #[test]
+    fn load_labeled_errors_on_unknown_label_instead_of_loading_whole_file() {
+        let mut mgr: AssetManager<Labeled> = AssetManager::new();
+        let mut handle = AssetHandle::new("atlas.png#missing");
+
+        let err = mgr.load(&mut handle, &()).unwrap_err().clone();
+        assert!(err.contains("missing"));
+        assert!(handle.is_err());
+    }
+
+    #[test]
+    fn distinct_labels_on_same_path_cache_as_independent_slots() {
+        let mut mgr: AssetManager<Labeled> = AssetManager::new();
+        let mut left = AssetHandle::new("atlas.png#walk_left");
+        let mut right = AssetHandle::new("atlas.png#walk_right");
+        mgr.load(&mut left, &()).unwrap();
+        mgr.load(&mut right, &()).unwrap();
+
+        assert_eq!(
+            mgr.get(&left),
+            Some(&Labeled("atlas.png#walk_left".to_string()))
+        );
+        assert_eq!(
+            mgr.get(&right),
+            Some(&Labeled("atlas.png#walk_right".to_string()))
+        );
+    }
 }